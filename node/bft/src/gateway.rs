@@ -95,6 +95,21 @@ pub trait Transport<N: Network>: Send + Sync {
     fn broadcast(&self, event: Event<N>);
 }
 
+/// The connectivity and stake of a committee member.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemberInfo<N: Network> {
+    /// The address of the member.
+    pub address: Address<N>,
+    /// The stake of the member.
+    pub stake: u64,
+    /// Whether the member is connected.
+    pub is_connected: bool,
+    /// The listener IP of the member, if it is mapped.
+    pub peer_ip: Option<SocketAddr>,
+    /// Whether the member is eligible to be elected leader, i.e. it is in the committee used for leader election.
+    pub is_leader_eligible: bool,
+}
+
 #[derive(Clone)]
 pub struct Gateway<N: Network> {
     /// The account of the node.
@@ -282,6 +297,21 @@ impl<N: Network> Gateway<N> {
         self.primary_sender.get().expect("Primary sender not set in gateway")
     }
 
+    /// Returns the primary sender, or an error if the gateway has not been started yet.
+    pub fn try_primary_sender(&self) -> Result<&PrimarySender<N>> {
+        self.primary_sender.get().ok_or_else(|| anyhow!("Primary sender not set in gateway"))
+    }
+
+    /// Returns `true` if the primary sender is set and can accept events without waiting.
+    pub fn primary_sender_is_ready(&self) -> bool {
+        self.primary_sender.get().map_or(false, |sender| sender.is_ready())
+    }
+
+    /// Returns the remaining capacity of the primary sender, if it is set.
+    pub fn primary_sender_capacity(&self) -> Option<usize> {
+        self.primary_sender.get().map(|sender| sender.capacity())
+    }
+
     /// Returns the number of workers.
     pub fn num_workers(&self) -> u8 {
         u8::try_from(self.worker_senders.get().expect("Missing worker senders in gateway").len())
@@ -343,6 +373,17 @@ impl<N: Network> Gateway<N> {
                 .map_or(false, |committee| committee.is_committee_member(validator_address))
     }
 
+    /// Returns the stake of the given peer IP in the current committee.
+    /// If the peer IP is unknown, or is not a committee member, this returns `0`.
+    pub fn get_stake_by_peer(&self, peer_ip: SocketAddr) -> u64 {
+        // Retrieve the Aleo address of the peer IP.
+        match self.resolver.get_address(peer_ip) {
+            // Retrieve the stake of the address in the current committee.
+            Some(address) => self.ledger.current_committee().map_or(0, |committee| committee.get_stake(address)),
+            None => 0,
+        }
+    }
+
     /// Returns the maximum number of connected peers.
     pub fn max_connected_peers(&self) -> usize {
         self.tcp.config().max_connections as usize
@@ -358,6 +399,53 @@ impl<N: Network> Gateway<N> {
         self.connected_peers.read().iter().filter_map(|peer_ip| self.resolver.get_address(*peer_ip)).collect()
     }
 
+    /// Returns the addresses of the connected peers that are members of the current committee.
+    pub fn connected_members(&self) -> Vec<Address<N>> {
+        match self.ledger.current_committee() {
+            Ok(committee) => self
+                .connected_addresses()
+                .into_iter()
+                .filter(|address| committee.is_committee_member(*address))
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Returns the total stake of the connected peers in the current committee.
+    pub fn connected_stake(&self) -> Result<u64> {
+        let committee = self.ledger.current_committee()?;
+        Ok(self.connected_addresses().iter().map(|address| committee.get_stake(*address)).sum())
+    }
+
+    /// Returns the members of the current committee, sorted connected-first, then by stake (descending).
+    pub fn ranked_members(&self) -> Vec<MemberInfo<N>> {
+        let Ok(committee) = self.ledger.current_committee() else {
+            return Vec::new();
+        };
+        // Retrieve the committee used for leader election.
+        let leader_committee = self.ledger.get_previous_committee_for_round(self.ledger.latest_round()).ok();
+        // Construct the member info for each member.
+        let mut members = committee
+            .members()
+            .keys()
+            .map(|address| {
+                let peer_ip = self.resolver.get_peer_ip_for_address(*address);
+                MemberInfo {
+                    address: *address,
+                    stake: committee.get_stake(*address),
+                    is_connected: peer_ip.map_or(false, |peer_ip| self.is_connected_ip(peer_ip)),
+                    peer_ip,
+                    is_leader_eligible: leader_committee
+                        .as_ref()
+                        .map_or(false, |leader_committee| leader_committee.is_committee_member(*address)),
+                }
+            })
+            .collect::<Vec<_>>();
+        // Sort the members by connected members first, then by stake in descending order.
+        members.sort_by(|a, b| b.is_connected.cmp(&a.is_connected).then(b.stake.cmp(&a.stake)));
+        members
+    }
+
     /// Returns the list of connected peers.
     pub fn connected_peers(&self) -> &RwLock<IndexSet<SocketAddr>> {
         &self.connected_peers
@@ -433,6 +521,18 @@ impl<N: Network> Gateway<N> {
     fn update_metrics(&self) {
         metrics::gauge(metrics::bft::CONNECTED, self.connected_peers.read().len() as f64);
         metrics::gauge(metrics::bft::CONNECTING, self.connecting_peers.lock().len() as f64);
+        let [from_listener, to_listener, peer_addresses, address_peers] = self.resolver.peer_map_lengths();
+        metrics::gauge(metrics::bft::RESOLVER_FROM_LISTENER, from_listener as f64);
+        metrics::gauge(metrics::bft::RESOLVER_TO_LISTENER, to_listener as f64);
+        metrics::gauge(metrics::bft::RESOLVER_PEER_ADDRESSES, peer_addresses as f64);
+        metrics::gauge(metrics::bft::RESOLVER_ADDRESS_PEERS, address_peers as f64);
+        if let Ok(committee) = self.ledger.current_committee() {
+            metrics::gauge(metrics::bft::COMMITTEE_SIZE, committee.num_members() as f64);
+            metrics::gauge(metrics::bft::COMMITTEE_TOTAL_STAKE, committee.total_stake() as f64);
+        }
+        if let Ok(connected_stake) = self.connected_stake() {
+            metrics::gauge(metrics::bft::COMMITTEE_CONNECTED_STAKE, connected_stake as f64);
+        }
     }
 
     /// Inserts the given peer into the connected peers.
@@ -841,12 +941,19 @@ impl<N: Network> Gateway<N> {
     /// Handles the heartbeat request.
     fn heartbeat(&self) {
         self.log_connected_validators();
+        // Refresh the metrics, as the committee may have changed.
+        #[cfg(feature = "metrics")]
+        self.update_metrics();
         // Keep the trusted validators connected.
         self.handle_trusted_validators();
         // Removes any validators that not in the current committee.
         self.handle_unauthorized_validators();
         // If the number of connected validators is less than the minimum, send a `ValidatorsRequest`.
         self.handle_min_connected_validators();
+        // Removes the resolver's mappings for any peers that are no longer connected.
+        self.handle_stale_peer_mappings();
+        // Reconcile the resolver's peer maps, if they are inconsistent.
+        self.handle_resolver_consistency();
     }
 
     /// Logs the connected validators.
@@ -904,6 +1011,20 @@ impl<N: Network> Gateway<N> {
         });
     }
 
+    /// This function removes the resolver's mappings for any peers that are no longer connected.
+    fn handle_stale_peer_mappings(&self) {
+        // Note: Peers are mapped during the handshake, while they are still connecting, so they are kept.
+        self.resolver.evict_peers(|peer_ip| self.is_connected_ip(peer_ip) || self.is_connecting_ip(peer_ip));
+    }
+
+    /// This function reconciles the resolver's peer maps, if they are inconsistent.
+    fn handle_resolver_consistency(&self) {
+        if !self.resolver.peer_map_consistency() {
+            warn!("{CONTEXT} Reconciling the resolver's peer maps - Peer maps are inconsistent");
+            self.resolver.reconcile_peer_maps();
+        }
+    }
+
     /// This function sends a `ValidatorsRequest` to a random validator,
     /// if the number of connected validators is less than the minimum.
     fn handle_min_connected_validators(&self) {
@@ -1340,7 +1461,8 @@ mod prop_tests {
             prop_tests::{CommitteeContext, ValidatorSet},
             Committee,
         },
-        prelude::{PrivateKey, Testnet3},
+        prelude::{Address, PrivateKey, Rng, Testnet3},
+        utilities::TestRng,
     };
 
     use indexmap::IndexMap;
@@ -1349,6 +1471,7 @@ mod prop_tests {
         sample::Selector,
     };
     use std::{
+        collections::HashSet,
         fmt::{Debug, Formatter},
         net::{IpAddr, Ipv4Addr, SocketAddr},
         sync::Arc,
@@ -1438,6 +1561,13 @@ mod prop_tests {
             .boxed()
     }
 
+    fn sample_gateway(rng: &mut TestRng) -> Gateway<CurrentNetwork> {
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        let ledger = Arc::new(MockLedgerService::new(committee));
+        let account = Account::new(rng).unwrap();
+        Gateway::new(account, ledger, None, &[], Some(0)).unwrap()
+    }
+
     #[proptest]
     fn gateway_dev_initialization(#[strategy(any_valid_dev_gateway())] input: GatewayInput) {
         let (storage, _, private_key, dev) = input;
@@ -1473,6 +1603,160 @@ mod prop_tests {
         assert_eq!(gateway.account().address(), account.address());
     }
 
+    #[test]
+    fn gateway_try_primary_sender() {
+        let rng = &mut TestRng::default();
+        let gateway = sample_gateway(rng);
+
+        // The primary sender is only set once the gateway is started.
+        assert!(gateway.try_primary_sender().is_err());
+
+        // Set the primary sender, as in `Gateway::run`.
+        let (primary_sender, _primary_receiver) = init_primary_channels();
+        gateway.primary_sender.set(primary_sender).unwrap();
+        assert!(gateway.try_primary_sender().is_ok());
+    }
+
+    #[test]
+    fn gateway_primary_sender_readiness() {
+        let rng = &mut TestRng::default();
+        let gateway = sample_gateway(rng);
+
+        // The primary sender is not ready until it is set.
+        assert!(!gateway.primary_sender_is_ready());
+        assert!(gateway.primary_sender_capacity().is_none());
+
+        // Set the primary sender, as in `Gateway::run`.
+        let (primary_sender, primary_receiver) = init_primary_channels();
+        gateway.primary_sender.set(primary_sender).unwrap();
+        assert!(gateway.primary_sender_is_ready());
+        assert!(gateway.primary_sender_capacity().unwrap() > 0);
+
+        // The primary sender is no longer ready once the primary stops receiving.
+        drop(primary_receiver);
+        assert!(!gateway.primary_sender_is_ready());
+    }
+
+    #[test]
+    fn gateway_get_stake_by_peer() {
+        let rng = &mut TestRng::default();
+        let gateway = sample_gateway(rng);
+        let committee = gateway.ledger.current_committee().unwrap();
+        let member = *committee.members().keys().next().unwrap();
+        let stake = committee.get_stake(member);
+
+        let member_ip = SocketAddr::from(([127, 0, 0, 1], 1234));
+        let non_member_ip = SocketAddr::from(([127, 0, 0, 1], 1235));
+        let unknown_ip = SocketAddr::from(([127, 0, 0, 1], 1236));
+        gateway.insert_connected_peer(member_ip, SocketAddr::from(([127, 0, 0, 1], 4321)), member);
+        gateway.insert_connected_peer(non_member_ip, SocketAddr::from(([127, 0, 0, 1], 4322)), Address::new(rng.gen()));
+
+        // Ensure a known member resolves to its stake.
+        assert!(stake > 0);
+        assert_eq!(gateway.get_stake_by_peer(member_ip), stake);
+        // Ensure a non-member and an unknown peer have no stake.
+        assert_eq!(gateway.get_stake_by_peer(non_member_ip), 0);
+        assert_eq!(gateway.get_stake_by_peer(unknown_ip), 0);
+    }
+
+    #[test]
+    fn gateway_connected_members() {
+        let rng = &mut TestRng::default();
+        let gateway = sample_gateway(rng);
+        let committee = gateway.ledger.current_committee().unwrap();
+        let members = committee.members().keys().copied().collect::<Vec<_>>();
+        assert!(members.len() > 2);
+
+        // Ensure there are no connected members before any peer is mapped.
+        assert!(gateway.connected_members().is_empty());
+        assert_eq!(gateway.connected_stake().unwrap(), 0);
+
+        // Connect two of the members, and a non-member. The remaining members have no peer mapping.
+        for (i, address) in (0u16..).zip([members[0], members[1], Address::new(rng.gen())]) {
+            let peer_ip = SocketAddr::from(([127, 0, 0, 1], 1000 + i));
+            gateway.insert_connected_peer(peer_ip, SocketAddr::from(([127, 0, 0, 1], 2000 + i)), address);
+        }
+
+        // Ensure only the connected members are returned.
+        let connected_members = gateway.connected_members().into_iter().collect::<HashSet<_>>();
+        assert_eq!(connected_members, HashSet::from([members[0], members[1]]));
+        // Ensure the connected stake is the stake of the connected members.
+        let expected_stake = committee.get_stake(members[0]) + committee.get_stake(members[1]);
+        assert_eq!(gateway.connected_stake().unwrap(), expected_stake);
+        assert!(expected_stake < committee.total_stake());
+    }
+
+    #[test]
+    fn gateway_ranked_members() {
+        let rng = &mut TestRng::default();
+        let gateway = sample_gateway(rng);
+        let committee = gateway.ledger.current_committee().unwrap();
+        let members = committee.members().keys().copied().collect::<Vec<_>>();
+        assert!(members.len() > 3);
+
+        // Connect two of the members, and map a third member without connecting it.
+        gateway.insert_connected_peer(
+            SocketAddr::from(([127, 0, 0, 1], 1000)),
+            SocketAddr::from(([127, 0, 0, 1], 2000)),
+            members[1],
+        );
+        gateway.insert_connected_peer(
+            SocketAddr::from(([127, 0, 0, 1], 1001)),
+            SocketAddr::from(([127, 0, 0, 1], 2001)),
+            members[3],
+        );
+        gateway.resolver().insert_peer(
+            SocketAddr::from(([127, 0, 0, 1], 1002)),
+            SocketAddr::from(([127, 0, 0, 1], 2002)),
+            members[2],
+        );
+
+        let ranked = gateway.ranked_members();
+        assert_eq!(ranked.len(), members.len());
+        // Ensure the connected members are listed first.
+        let connected = ranked.iter().take(2).map(|member| member.address).collect::<HashSet<_>>();
+        assert_eq!(connected, HashSet::from([members[1], members[3]]));
+        assert!(ranked.iter().take(2).all(|member| member.is_connected && member.peer_ip.is_some()));
+        assert!(ranked.iter().skip(2).all(|member| !member.is_connected));
+        // Ensure the mapped member reports its peer IP, even though it is not connected.
+        let mapped = ranked.iter().find(|member| member.address == members[2]).unwrap();
+        assert_eq!(mapped.peer_ip, Some(SocketAddr::from(([127, 0, 0, 1], 1002))));
+        // Ensure each group is sorted by stake in descending order.
+        assert!(ranked[..2].windows(2).all(|pair| pair[0].stake >= pair[1].stake));
+        assert!(ranked[2..].windows(2).all(|pair| pair[0].stake >= pair[1].stake));
+        // Ensure the stake and leader eligibility are reported for each member.
+        for member in &ranked {
+            assert_eq!(member.stake, committee.get_stake(member.address));
+            assert!(member.is_leader_eligible);
+        }
+    }
+
+    #[test]
+    fn gateway_stale_peer_mappings() {
+        let rng = &mut TestRng::default();
+        let gateway = sample_gateway(rng);
+        let (connected_ip, connecting_ip, stale_ip) = (
+            SocketAddr::from(([127, 0, 0, 1], 1000)),
+            SocketAddr::from(([127, 0, 0, 1], 1001)),
+            SocketAddr::from(([127, 0, 0, 1], 1002)),
+        );
+        gateway.insert_connected_peer(connected_ip, SocketAddr::from(([127, 0, 0, 1], 2000)), Address::new(rng.gen()));
+        // Seed a mapping for a peer that is still connecting, and a stale mapping for a disconnected peer.
+        let resolver = gateway.resolver();
+        gateway.connecting_peers.lock().insert(connecting_ip);
+        resolver.insert_peer(connecting_ip, SocketAddr::from(([127, 0, 0, 1], 2001)), Address::new(rng.gen()));
+        resolver.insert_peer(stale_ip, SocketAddr::from(([127, 0, 0, 1], 2002)), Address::new(rng.gen()));
+
+        gateway.handle_stale_peer_mappings();
+
+        // Ensure only the stale mapping is removed.
+        assert!(resolver.contains_peer(connected_ip));
+        assert!(resolver.contains_peer(connecting_ip));
+        assert!(!resolver.contains_peer(stale_ip));
+        assert!(resolver.get_ambiguous(stale_ip).is_none());
+        assert!(resolver.peer_map_consistency());
+    }
+
     #[proptest(async = "tokio")]
     async fn gateway_start(
         #[strategy(any_valid_dev_gateway())] input: GatewayInput,
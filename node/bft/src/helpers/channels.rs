@@ -160,6 +160,32 @@ pub struct PrimarySender<N: Network> {
 }
 
 impl<N: Network> PrimarySender<N> {
+    /// Returns the smallest remaining capacity across the primary channels.
+    pub fn capacity(&self) -> usize {
+        [
+            self.tx_batch_propose.capacity(),
+            self.tx_batch_signature.capacity(),
+            self.tx_batch_certified.capacity(),
+            self.tx_primary_ping.capacity(),
+            self.tx_unconfirmed_solution.capacity(),
+            self.tx_unconfirmed_transaction.capacity(),
+        ]
+        .into_iter()
+        .min()
+        .unwrap_or(0)
+    }
+
+    /// Returns `true` if every primary channel is open and has remaining capacity.
+    pub fn is_ready(&self) -> bool {
+        let is_closed = self.tx_batch_propose.is_closed()
+            || self.tx_batch_signature.is_closed()
+            || self.tx_batch_certified.is_closed()
+            || self.tx_primary_ping.is_closed()
+            || self.tx_unconfirmed_solution.is_closed()
+            || self.tx_unconfirmed_transaction.is_closed();
+        !is_closed && self.capacity() > 0
+    }
+
     /// Sends the unconfirmed solution to the primary.
     pub async fn send_unconfirmed_solution(
         &self,
@@ -324,3 +350,31 @@ pub fn init_sync_channels<N: Network>() -> (SyncSender<N>, SyncReceiver<N>) {
 
     (sender, receiver)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm::prelude::Testnet3;
+
+    #[test]
+    fn test_primary_sender_readiness() {
+        let (sender, receiver) = init_primary_channels::<CurrentNetwork>();
+        assert_eq!(sender.capacity(), MAX_CHANNEL_SIZE);
+        assert!(sender.is_ready());
+
+        // Fill one of the channels.
+        let permits = (0..MAX_CHANNEL_SIZE).map(|_| sender.tx_batch_propose.try_reserve().unwrap()).collect::<Vec<_>>();
+        assert_eq!(sender.capacity(), 0);
+        assert!(!sender.is_ready());
+
+        // Release the channel.
+        drop(permits);
+        assert_eq!(sender.capacity(), MAX_CHANNEL_SIZE);
+        assert!(sender.is_ready());
+
+        // Close the channels.
+        drop(receiver);
+        assert!(!sender.is_ready());
+    }
+}
@@ -14,19 +14,131 @@
 
 use snarkvm::prelude::{Address, Network};
 
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::{collections::HashMap, net::SocketAddr};
 
 #[derive(Debug)]
-pub struct Resolver<N: Network> {
+struct ResolverInner<N: Network> {
     /// The map of the listener address to (ambiguous) peer address.
-    from_listener: RwLock<HashMap<SocketAddr, SocketAddr>>,
+    from_listener: HashMap<SocketAddr, SocketAddr>,
     /// The map of the (ambiguous) peer address to listener address.
-    to_listener: RwLock<HashMap<SocketAddr, SocketAddr>>,
+    to_listener: HashMap<SocketAddr, SocketAddr>,
     /// A map of `peer IP` to `address`.
-    peer_addresses: RwLock<HashMap<SocketAddr, Address<N>>>,
+    peer_addresses: HashMap<SocketAddr, Address<N>>,
     /// A map of `address` to `peer IP`.
-    address_peers: RwLock<HashMap<Address<N>, SocketAddr>>,
+    address_peers: HashMap<Address<N>, SocketAddr>,
+}
+
+impl<N: Network> Default for ResolverInner<N> {
+    fn default() -> Self {
+        Self {
+            from_listener: Default::default(),
+            to_listener: Default::default(),
+            peer_addresses: Default::default(),
+            address_peers: Default::default(),
+        }
+    }
+}
+
+impl<N: Network> ResolverInner<N> {
+    /// Inserts the mappings for the given listener address, removing any stale mappings.
+    fn insert_peer(&mut self, listener_ip: SocketAddr, peer_addr: SocketAddr, address: Address<N>) {
+        // Remove the stale listener address for the Aleo address, if it exists.
+        if let Some(previous_ip) = self.address_peers.get(&address).copied() {
+            if previous_ip != listener_ip {
+                self.remove_peer(previous_ip);
+            }
+        }
+        // Remove the stale listener address for the (ambiguous) peer address, if it exists.
+        if let Some(previous_ip) = self.to_listener.get(&peer_addr).copied() {
+            if previous_ip != listener_ip {
+                self.remove_peer(previous_ip);
+            }
+        }
+        // Insert the (ambiguous) peer address, removing the stale one for the listener address, if it exists.
+        if let Some(previous_addr) = self.from_listener.insert(listener_ip, peer_addr) {
+            if previous_addr != peer_addr {
+                self.to_listener.remove(&previous_addr);
+            }
+        }
+        // Insert the Aleo address, removing the stale one for the listener address, if it exists.
+        if let Some(previous_address) = self.peer_addresses.insert(listener_ip, address) {
+            if previous_address != address {
+                self.address_peers.remove(&previous_address);
+            }
+        }
+        self.to_listener.insert(peer_addr, listener_ip);
+        self.address_peers.insert(address, listener_ip);
+    }
+
+    /// Returns the (ambiguous) peer address and the Aleo address for the given listener address.
+    fn mappings(&self, listener_ip: SocketAddr) -> (Option<SocketAddr>, Option<Address<N>>) {
+        (self.from_listener.get(&listener_ip).copied(), self.peer_addresses.get(&listener_ip).copied())
+    }
+
+    /// Removes the mappings for the given listener address.
+    fn remove_peer(&mut self, listener_ip: SocketAddr) {
+        // Note: The reverse mappings are only removed if they map back to the listener address.
+        if let Some(peer_addr) = self.from_listener.remove(&listener_ip) {
+            if self.to_listener.get(&peer_addr) == Some(&listener_ip) {
+                self.to_listener.remove(&peer_addr);
+            }
+        }
+        if let Some(address) = self.peer_addresses.remove(&listener_ip) {
+            if self.address_peers.get(&address) == Some(&listener_ip) {
+                self.address_peers.remove(&address);
+            }
+        }
+    }
+
+    /// Returns `true` if all four mappings of the given listener address exist and map back to it.
+    fn is_consistent_peer(&self, listener_ip: SocketAddr) -> bool {
+        match self.mappings(listener_ip) {
+            (Some(peer_addr), Some(address)) => {
+                self.to_listener.get(&peer_addr) == Some(&listener_ip)
+                    && self.address_peers.get(&address) == Some(&listener_ip)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the peer maps are one-to-one, i.e. every listener address is fully mapped,
+    /// and each pair of maps are mutual inverses of each other.
+    fn is_consistent(&self) -> bool {
+        // Note: If the maps have equal lengths and every listener address is fully mapped back, they are inverses.
+        self.from_listener.len() == self.peer_addresses.len()
+            && self.from_listener.len() == self.to_listener.len()
+            && self.peer_addresses.len() == self.address_peers.len()
+            && self.from_listener.keys().all(|listener_ip| self.is_consistent_peer(*listener_ip))
+    }
+
+    /// Removes every listener address that is not fully mapped, along with any orphaned reverse mappings.
+    fn reconcile(&mut self) {
+        // Remove every mapping of each inconsistent listener address.
+        let inconsistent = self
+            .from_listener
+            .keys()
+            .chain(self.peer_addresses.keys())
+            .copied()
+            .filter(|listener_ip| !self.is_consistent_peer(*listener_ip))
+            .collect::<IndexSet<_>>();
+        for listener_ip in inconsistent {
+            self.remove_peer(listener_ip);
+        }
+        // Remove the reverse mappings that are not mapped back.
+        let from_listener = &self.from_listener;
+        self.to_listener.retain(|peer_addr, listener_ip| from_listener.get(listener_ip) == Some(peer_addr));
+        let peer_addresses = &self.peer_addresses;
+        self.address_peers.retain(|address, listener_ip| peer_addresses.get(listener_ip) == Some(address));
+    }
+}
+
+#[derive(Debug)]
+pub struct Resolver<N: Network> {
+    /// The peer mappings, guarded by a single lock so that the paired writes of each mutation are
+    /// one critical section. The lock does not poison, so a writer that panics cannot wedge the resolver.
+    inner: RwLock<ResolverInner<N>>,
 }
 
 impl<N: Network> Default for Resolver<N> {
@@ -39,53 +151,137 @@ impl<N: Network> Default for Resolver<N> {
 impl<N: Network> Resolver<N> {
     /// Initializes a new instance of the resolver.
     pub fn new() -> Self {
-        Self {
-            from_listener: Default::default(),
-            to_listener: Default::default(),
-            peer_addresses: Default::default(),
-            address_peers: Default::default(),
-        }
+        Self { inner: Default::default() }
     }
 }
 
 impl<N: Network> Resolver<N> {
     /// Returns the listener address for the given (ambiguous) peer address, if it exists.
     pub fn get_listener(&self, peer_addr: SocketAddr) -> Option<SocketAddr> {
-        self.to_listener.read().get(&peer_addr).copied()
+        self.inner.read().to_listener.get(&peer_addr).copied()
     }
 
     /// Returns the (ambiguous) peer address for the given listener address, if it exists.
     pub fn get_ambiguous(&self, peer_ip: SocketAddr) -> Option<SocketAddr> {
-        self.from_listener.read().get(&peer_ip).copied()
+        self.inner.read().from_listener.get(&peer_ip).copied()
     }
 
     /// Returns the address for the given peer IP.
     pub fn get_address(&self, peer_ip: SocketAddr) -> Option<Address<N>> {
-        self.peer_addresses.read().get(&peer_ip).copied()
+        self.inner.read().peer_addresses.get(&peer_ip).copied()
     }
 
     /// Returns the peer IP for the given address.
     pub fn get_peer_ip_for_address(&self, address: Address<N>) -> Option<SocketAddr> {
-        self.address_peers.read().get(&address).copied()
+        self.inner.read().address_peers.get(&address).copied()
+    }
+
+    /// Returns `true` if the given peer IP is mapped to an address.
+    pub fn contains_peer(&self, peer_ip: SocketAddr) -> bool {
+        self.inner.read().peer_addresses.contains_key(&peer_ip)
+    }
+
+    /// Returns the number of peer IPs that are mapped to an address.
+    pub fn mapped_peer_count(&self) -> usize {
+        self.inner.read().peer_addresses.len()
+    }
+
+    /// Returns the lengths of the peer maps, in the order:
+    /// `from_listener`, `to_listener`, `peer_addresses`, `address_peers`.
+    pub fn peer_map_lengths(&self) -> [usize; 4] {
+        let inner = self.inner.read();
+        [inner.from_listener.len(), inner.to_listener.len(), inner.peer_addresses.len(), inner.address_peers.len()]
+    }
+
+    /// Returns the peer IPs of every mapped address.
+    pub fn all_peer_ips(&self) -> Vec<SocketAddr> {
+        // Note: Each peer IP is mapped to at most one address, so the peer IPs are unique.
+        self.inner.read().address_peers.values().copied().collect()
+    }
+
+    /// Returns the peer IPs and their addresses, sorted by peer IP.
+    pub fn peers_sorted(&self) -> Vec<(SocketAddr, Address<N>)> {
+        let mut peers =
+            self.inner.read().peer_addresses.iter().map(|(peer_ip, address)| (*peer_ip, *address)).collect::<Vec<_>>();
+        peers.sort_unstable_by_key(|(peer_ip, _)| *peer_ip);
+        peers
+    }
+
+    /// Returns `true` if the peer maps are consistent, i.e. each pair of maps are mutual inverses.
+    pub fn peer_map_consistency(&self) -> bool {
+        self.inner.read().is_consistent()
+    }
+
+    /// Removes any mappings that are not reversed by their inverse map, restoring consistency.
+    pub fn reconcile_peer_maps(&self) {
+        self.inner.write().reconcile();
     }
 
     /// Inserts a bidirectional mapping of the listener address and the (ambiguous) peer address,
     /// alongside a bidirectional mapping of the listener address and the Aleo address.
+    ///
+    /// If the Aleo address or the (ambiguous) peer address was previously mapped to a different
+    /// listener address, all mappings of that stale listener address are removed. If the listener
+    /// address was previously mapped to a different peer address or Aleo address, those stale
+    /// reverse mappings are removed. This keeps all four maps one-to-one.
     pub fn insert_peer(&self, listener_ip: SocketAddr, peer_addr: SocketAddr, address: Address<N>) {
-        self.from_listener.write().insert(listener_ip, peer_addr);
-        self.to_listener.write().insert(peer_addr, listener_ip);
-        self.peer_addresses.write().insert(listener_ip, address);
-        self.address_peers.write().insert(address, listener_ip);
+        self.inner.write().insert_peer(listener_ip, peer_addr, address);
+    }
+
+    /// Inserts the mappings for each of the given `(listener address, peer address, Aleo address)` entries,
+    /// under a single acquisition of the lock. Stale mappings are removed as in `insert_peer`.
+    /// Note: Each entry includes the (ambiguous) peer address, as it is mapped to the listener address too.
+    #[allow(dead_code)]
+    pub(crate) fn insert_peers(&self, entries: impl IntoIterator<Item = (SocketAddr, SocketAddr, Address<N>)>) {
+        let mut inner = self.inner.write();
+        for (listener_ip, peer_addr, address) in entries {
+            inner.insert_peer(listener_ip, peer_addr, address);
+        }
     }
 
     /// Removes the bidirectional mapping of the listener address and the (ambiguous) peer address,
     /// alongside the bidirectional mapping of the listener address and the Aleo address.
     pub fn remove_peer(&self, listener_ip: SocketAddr) {
-        if let Some(peer_addr) = self.from_listener.write().remove(&listener_ip) {
-            self.to_listener.write().remove(&peer_addr);
+        self.inner.write().remove_peer(listener_ip);
+    }
+
+    /// Removes the mappings of the listener address that is mapped to the given Aleo address, if it exists.
+    #[allow(dead_code)]
+    pub(crate) fn remove_peer_by_address(&self, address: Address<N>) {
+        let mut inner = self.inner.write();
+        if let Some(listener_ip) = inner.address_peers.get(&address).copied() {
+            inner.remove_peer(listener_ip);
+        }
+    }
+
+    /// Removes the mappings of every listener address for which `keep` returns `false`.
+    ///
+    /// Note: `keep` is evaluated without holding the lock, so it may safely call into the resolver
+    /// or take other locks (e.g. the gateway's connected peers). A listener address that is re-mapped
+    /// in the meantime (e.g. the peer reconnected) keeps its fresh mappings.
+    pub fn evict_peers(&self, keep: impl Fn(SocketAddr) -> bool) {
+        // Snapshot the mappings of each listener address.
+        let snapshot = {
+            let inner = self.inner.read();
+            inner
+                .from_listener
+                .keys()
+                .chain(inner.peer_addresses.keys())
+                .map(|listener_ip| (*listener_ip, inner.mappings(*listener_ip)))
+                .collect::<IndexMap<_, _>>()
+        };
+        // Determine the listener addresses to evict.
+        let evicted = snapshot.into_iter().filter(|(listener_ip, _)| !keep(*listener_ip)).collect::<Vec<_>>();
+        if evicted.is_empty() {
+            return;
         }
-        if let Some(address) = self.peer_addresses.write().remove(&listener_ip) {
-            self.address_peers.write().remove(&address);
+        // Remove the mappings for each evicted listener address.
+        let mut inner = self.inner.write();
+        for (listener_ip, mappings) in evicted {
+            // Skip the listener address if it was re-mapped since the snapshot (e.g. the peer reconnected).
+            if inner.mappings(listener_ip) == mappings {
+                inner.remove_peer(listener_ip);
+            }
         }
     }
 }
@@ -97,6 +293,16 @@ mod tests {
 
     type CurrentNetwork = snarkvm::prelude::Testnet3;
 
+    /// Returns the sample listener address with the given index.
+    fn listener_ip(i: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], 1000 + i))
+    }
+
+    /// Returns the sample (ambiguous) peer address with the given index.
+    fn peer_addr(i: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], 2000 + i))
+    }
+
     #[test]
     fn test_resolver() {
         let resolver = Resolver::<CurrentNetwork>::new();
@@ -124,4 +330,383 @@ mod tests {
         assert!(resolver.get_ambiguous(listener_ip).is_none());
         assert!(resolver.get_peer_ip_for_address(address).is_none());
     }
+
+    #[test]
+    fn test_resolver_contains_peer() {
+        let resolver = Resolver::<CurrentNetwork>::new();
+        let listener_ip_1 = SocketAddr::from(([127, 0, 0, 1], 1234));
+        let listener_ip_2 = SocketAddr::from(([127, 0, 0, 1], 1235));
+        let peer_addr_1 = SocketAddr::from(([127, 0, 0, 1], 4321));
+        let peer_addr_2 = SocketAddr::from(([127, 0, 0, 1], 4322));
+        let mut rng = TestRng::default();
+
+        assert!(!resolver.contains_peer(listener_ip_1));
+        assert_eq!(resolver.mapped_peer_count(), 0);
+
+        resolver.insert_peer(listener_ip_1, peer_addr_1, Address::new(rng.gen()));
+        resolver.insert_peer(listener_ip_2, peer_addr_2, Address::new(rng.gen()));
+        assert!(resolver.contains_peer(listener_ip_1));
+        assert!(resolver.contains_peer(listener_ip_2));
+        assert_eq!(resolver.mapped_peer_count(), 2);
+        assert_eq!(resolver.peer_map_lengths(), [2; 4]);
+
+        resolver.remove_peer(listener_ip_1);
+        assert!(!resolver.contains_peer(listener_ip_1));
+        assert!(resolver.contains_peer(listener_ip_2));
+        assert_eq!(resolver.mapped_peer_count(), 1);
+
+        // Ensure removing an unknown peer is a no-op.
+        resolver.remove_peer(listener_ip_1);
+        assert_eq!(resolver.mapped_peer_count(), 1);
+    }
+
+    #[test]
+    fn test_resolver_all_peer_ips() {
+        let resolver = Resolver::<CurrentNetwork>::new();
+        let mut rng = TestRng::default();
+        let addresses = (0..3).map(|_| Address::<CurrentNetwork>::new(rng.gen())).collect::<Vec<_>>();
+
+        resolver.insert_peer(listener_ip(0), peer_addr(0), addresses[0]);
+        resolver.insert_peer(listener_ip(1), peer_addr(1), addresses[1]);
+        resolver.insert_peer(listener_ip(2), peer_addr(2), addresses[2]);
+        // Churn the peers.
+        resolver.remove_peer(listener_ip(1));
+        resolver.insert_peer(listener_ip(3), peer_addr(3), addresses[0]);
+
+        let mut peer_ips = resolver.all_peer_ips();
+        peer_ips.sort_unstable();
+        assert_eq!(peer_ips, vec![listener_ip(2), listener_ip(3)]);
+    }
+
+    #[test]
+    fn test_resolver_peers_sorted() {
+        let mut rng = TestRng::default();
+        let peers = (0..5u16).map(|i| (listener_ip(i), Address::<CurrentNetwork>::new(rng.gen()))).collect::<Vec<_>>();
+
+        // Insert the same peers into two resolvers, in different orders.
+        let resolver_a = Resolver::<CurrentNetwork>::new();
+        let resolver_b = Resolver::<CurrentNetwork>::new();
+        for (i, (listener_ip, address)) in peers.iter().enumerate() {
+            resolver_a.insert_peer(*listener_ip, peer_addr(i as u16), *address);
+        }
+        for (i, (listener_ip, address)) in peers.iter().enumerate().rev() {
+            resolver_b.insert_peer(*listener_ip, peer_addr(i as u16), *address);
+        }
+
+        // Ensure both resolvers report the peers in the same order.
+        assert_eq!(resolver_a.peers_sorted(), peers);
+        assert_eq!(resolver_b.peers_sorted(), peers);
+    }
+
+    #[test]
+    fn test_resolver_address_moves_to_new_ip() {
+        let resolver = Resolver::<CurrentNetwork>::new();
+        let listener_ip_1 = SocketAddr::from(([127, 0, 0, 1], 1234));
+        let listener_ip_2 = SocketAddr::from(([127, 0, 0, 1], 1235));
+        let peer_addr_1 = SocketAddr::from(([127, 0, 0, 1], 4321));
+        let peer_addr_2 = SocketAddr::from(([127, 0, 0, 1], 4322));
+        let mut rng = TestRng::default();
+        let address = Address::<CurrentNetwork>::new(rng.gen());
+
+        resolver.insert_peer(listener_ip_1, peer_addr_1, address);
+        resolver.insert_peer(listener_ip_2, peer_addr_2, address);
+
+        // Ensure only the new listener address resolves to the Aleo address.
+        assert!(resolver.get_address(listener_ip_1).is_none());
+        assert_eq!(resolver.get_address(listener_ip_2).unwrap(), address);
+        assert_eq!(resolver.get_peer_ip_for_address(address).unwrap(), listener_ip_2);
+        // Ensure the stale listener address is no longer mapped.
+        assert!(resolver.get_ambiguous(listener_ip_1).is_none());
+        assert!(resolver.get_listener(peer_addr_1).is_none());
+    }
+
+    #[test]
+    fn test_resolver_ip_moves_to_new_peer_addr() {
+        let resolver = Resolver::<CurrentNetwork>::new();
+        let listener_ip = SocketAddr::from(([127, 0, 0, 1], 1234));
+        let peer_addr_1 = SocketAddr::from(([127, 0, 0, 1], 4321));
+        let peer_addr_2 = SocketAddr::from(([127, 0, 0, 1], 4322));
+        let mut rng = TestRng::default();
+        let address = Address::<CurrentNetwork>::new(rng.gen());
+
+        resolver.insert_peer(listener_ip, peer_addr_1, address);
+        resolver.insert_peer(listener_ip, peer_addr_2, address);
+
+        // Ensure only the new peer address resolves to the listener address.
+        assert!(resolver.get_listener(peer_addr_1).is_none());
+        assert_eq!(resolver.get_listener(peer_addr_2).unwrap(), listener_ip);
+        assert_eq!(resolver.get_ambiguous(listener_ip).unwrap(), peer_addr_2);
+
+        // Ensure removing the peer clears every mapping.
+        resolver.remove_peer(listener_ip);
+        assert!(resolver.get_listener(peer_addr_1).is_none());
+        assert!(resolver.get_listener(peer_addr_2).is_none());
+        assert!(resolver.get_ambiguous(listener_ip).is_none());
+        assert!(resolver.get_address(listener_ip).is_none());
+        assert!(resolver.get_peer_ip_for_address(address).is_none());
+    }
+
+    #[test]
+    fn test_resolver_ip_moves_to_new_address() {
+        let resolver = Resolver::<CurrentNetwork>::new();
+        let listener_ip = SocketAddr::from(([127, 0, 0, 1], 1234));
+        let peer_addr = SocketAddr::from(([127, 0, 0, 1], 4321));
+        let mut rng = TestRng::default();
+        let address_a = Address::<CurrentNetwork>::new(rng.gen());
+        let address_b = Address::<CurrentNetwork>::new(rng.gen());
+
+        resolver.insert_peer(listener_ip, peer_addr, address_a);
+        resolver.insert_peer(listener_ip, peer_addr, address_b);
+
+        // Ensure the listener address only resolves to the new Aleo address.
+        assert_eq!(resolver.get_address(listener_ip).unwrap(), address_b);
+        assert_eq!(resolver.get_peer_ip_for_address(address_b).unwrap(), listener_ip);
+        assert!(resolver.get_peer_ip_for_address(address_a).is_none());
+    }
+
+    #[test]
+    fn test_resolver_remove_peer_by_address() {
+        let resolver = Resolver::<CurrentNetwork>::new();
+        let listener_ip = SocketAddr::from(([127, 0, 0, 1], 1234));
+        let peer_addr = SocketAddr::from(([127, 0, 0, 1], 4321));
+        let mut rng = TestRng::default();
+        let address = Address::<CurrentNetwork>::new(rng.gen());
+
+        resolver.insert_peer(listener_ip, peer_addr, address);
+        resolver.remove_peer_by_address(address);
+
+        assert!(resolver.get_listener(peer_addr).is_none());
+        assert!(resolver.get_address(listener_ip).is_none());
+        assert!(resolver.get_ambiguous(listener_ip).is_none());
+        assert!(resolver.get_peer_ip_for_address(address).is_none());
+
+        // Ensure removing an unknown address is a no-op.
+        resolver.remove_peer_by_address(address);
+        assert_eq!(resolver.mapped_peer_count(), 0);
+    }
+
+    #[test]
+    fn test_resolver_evict_peers() {
+        let resolver = Resolver::<CurrentNetwork>::new();
+        let live_ip = SocketAddr::from(([127, 0, 0, 1], 1234));
+        let dead_ip = SocketAddr::from(([127, 0, 0, 1], 1235));
+        let live_peer_addr = SocketAddr::from(([127, 0, 0, 1], 4321));
+        let dead_peer_addr = SocketAddr::from(([127, 0, 0, 1], 4322));
+        let mut rng = TestRng::default();
+        let live_address = Address::<CurrentNetwork>::new(rng.gen());
+        let dead_address = Address::<CurrentNetwork>::new(rng.gen());
+
+        resolver.insert_peer(live_ip, live_peer_addr, live_address);
+        resolver.insert_peer(dead_ip, dead_peer_addr, dead_address);
+
+        resolver.evict_peers(|peer_ip| peer_ip == live_ip);
+
+        // Ensure the dead peer is evicted.
+        assert!(resolver.get_listener(dead_peer_addr).is_none());
+        assert!(resolver.get_address(dead_ip).is_none());
+        assert!(resolver.get_ambiguous(dead_ip).is_none());
+        assert!(resolver.get_peer_ip_for_address(dead_address).is_none());
+        // Ensure the live peer remains.
+        assert_eq!(resolver.get_listener(live_peer_addr).unwrap(), live_ip);
+        assert_eq!(resolver.get_address(live_ip).unwrap(), live_address);
+        assert_eq!(resolver.get_ambiguous(live_ip).unwrap(), live_peer_addr);
+        assert_eq!(resolver.get_peer_ip_for_address(live_address).unwrap(), live_ip);
+    }
+
+    #[test]
+    fn test_resolver_evict_peers_reentrant() {
+        let resolver = Resolver::<CurrentNetwork>::new();
+        let mut rng = TestRng::default();
+        for i in 0..3 {
+            resolver.insert_peer(listener_ip(i), peer_addr(i), Address::new(rng.gen()));
+        }
+
+        // Ensure `keep` may call into the resolver, and is called once per listener address.
+        let calls = std::cell::Cell::new(0);
+        resolver.evict_peers(|peer_ip| {
+            calls.set(calls.get() + 1);
+            resolver.get_address(peer_ip).is_some() && peer_ip != listener_ip(1)
+        });
+        assert_eq!(calls.get(), 3);
+        assert_eq!(resolver.mapped_peer_count(), 2);
+        assert!(!resolver.contains_peer(listener_ip(1)));
+    }
+
+    #[test]
+    fn test_resolver_evict_peers_reconnect() {
+        let resolver = Resolver::<CurrentNetwork>::new();
+        let mut rng = TestRng::default();
+        let address = Address::<CurrentNetwork>::new(rng.gen());
+        resolver.insert_peer(listener_ip(0), peer_addr(0), address);
+
+        // Reconnect the peer from a new (ambiguous) peer address, after it is selected for eviction.
+        resolver.evict_peers(|peer_ip| {
+            resolver.insert_peer(peer_ip, peer_addr(1), address);
+            false
+        });
+
+        // Ensure the fresh mappings survive.
+        assert!(resolver.peer_map_consistency());
+        assert!(resolver.get_listener(peer_addr(0)).is_none());
+        assert_eq!(resolver.get_listener(peer_addr(1)).unwrap(), listener_ip(0));
+        assert_eq!(resolver.get_ambiguous(listener_ip(0)).unwrap(), peer_addr(1));
+        assert_eq!(resolver.get_address(listener_ip(0)).unwrap(), address);
+        assert_eq!(resolver.get_peer_ip_for_address(address).unwrap(), listener_ip(0));
+    }
+
+    #[test]
+    fn test_resolver_insert_peers() {
+        let resolver = Resolver::<CurrentNetwork>::new();
+        let mut rng = TestRng::default();
+        let addresses = (0..3).map(|_| Address::<CurrentNetwork>::new(rng.gen())).collect::<Vec<_>>();
+
+        resolver.insert_peers([
+            (listener_ip(0), peer_addr(0), addresses[0]),
+            (listener_ip(1), peer_addr(1), addresses[1]),
+            // The first address reconnects from a new listener address.
+            (listener_ip(2), peer_addr(2), addresses[0]),
+            // The second listener address is reused by a new address.
+            (listener_ip(1), peer_addr(1), addresses[2]),
+        ]);
+
+        // Ensure the stale mappings were removed.
+        assert!(resolver.get_address(listener_ip(0)).is_none());
+        assert!(resolver.get_ambiguous(listener_ip(0)).is_none());
+        assert!(resolver.get_listener(peer_addr(0)).is_none());
+        assert!(resolver.get_peer_ip_for_address(addresses[1]).is_none());
+        // Ensure the peer maps are inverses of each other.
+        assert_eq!(resolver.peers_sorted(), vec![(listener_ip(1), addresses[2]), (listener_ip(2), addresses[0])]);
+        for (peer_ip, address) in resolver.peers_sorted() {
+            assert_eq!(resolver.get_peer_ip_for_address(address), Some(peer_ip));
+        }
+    }
+
+    #[test]
+    fn test_resolver_panicking_writer() {
+        let resolver = Resolver::<CurrentNetwork>::new();
+        let listener_ip = SocketAddr::from(([127, 0, 0, 1], 1234));
+        let peer_addr = SocketAddr::from(([127, 0, 0, 1], 4321));
+        let mut rng = TestRng::default();
+        let address = Address::<CurrentNetwork>::new(rng.gen());
+
+        // Panic while holding the write lock, after a complete mutation of the peer maps.
+        // Note: This shows the lock is not poisoned, and the completed mutation remains intact.
+        // A partial write cannot be observed, as each mutation's paired writes are one critical section.
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut inner = resolver.inner.write();
+            inner.insert_peer(listener_ip, peer_addr, address);
+            panic!("writer panicked");
+        }));
+        assert!(result.is_err());
+
+        // Ensure the peer maps are still in sync.
+        assert!(resolver.peer_map_consistency());
+        assert_eq!(resolver.get_listener(peer_addr).unwrap(), listener_ip);
+        assert_eq!(resolver.get_ambiguous(listener_ip).unwrap(), peer_addr);
+        assert_eq!(resolver.get_address(listener_ip).unwrap(), address);
+        assert_eq!(resolver.get_peer_ip_for_address(address).unwrap(), listener_ip);
+
+        // Ensure the resolver is still usable.
+        resolver.remove_peer(listener_ip);
+        assert!(resolver.peer_map_consistency());
+        assert!(resolver.get_listener(peer_addr).is_none());
+        assert!(resolver.get_ambiguous(listener_ip).is_none());
+        assert!(resolver.get_address(listener_ip).is_none());
+        assert!(resolver.get_peer_ip_for_address(address).is_none());
+    }
+
+    #[test]
+    fn test_resolver_peer_map_consistency() {
+        let resolver = Resolver::<CurrentNetwork>::new();
+        let mut rng = TestRng::default();
+        let addresses = (0..3).map(|_| Address::<CurrentNetwork>::new(rng.gen())).collect::<Vec<_>>();
+        for i in 0..3 {
+            resolver.insert_peer(listener_ip(i), peer_addr(i), addresses[usize::from(i)]);
+        }
+        assert!(resolver.peer_map_consistency());
+
+        // Desync the Aleo address maps.
+        resolver.inner.write().address_peers.remove(&addresses[0]);
+        assert_eq!(resolver.peer_map_lengths(), [3, 3, 3, 2]);
+        assert!(!resolver.peer_map_consistency());
+        resolver.reconcile_peer_maps();
+        assert!(resolver.peer_map_consistency());
+        // Ensure the inconsistent listener address is removed as a whole.
+        assert!(resolver.get_listener(peer_addr(0)).is_none());
+        assert!(resolver.get_ambiguous(listener_ip(0)).is_none());
+        assert!(resolver.get_address(listener_ip(0)).is_none());
+        assert!(resolver.get_peer_ip_for_address(addresses[0]).is_none());
+
+        // Desync the listener address maps.
+        resolver.inner.write().to_listener.insert(peer_addr(1), listener_ip(2));
+        assert!(!resolver.peer_map_consistency());
+        resolver.reconcile_peer_maps();
+        assert!(resolver.peer_map_consistency());
+        // Ensure the inconsistent listener address is removed as a whole.
+        assert!(resolver.get_listener(peer_addr(1)).is_none());
+        assert!(resolver.get_ambiguous(listener_ip(1)).is_none());
+        assert!(resolver.get_address(listener_ip(1)).is_none());
+        assert!(resolver.get_peer_ip_for_address(addresses[1]).is_none());
+
+        // Desync the listener address from its Aleo address, by dropping only its peer address.
+        resolver.insert_peer(listener_ip(3), peer_addr(3), addresses[0]);
+        resolver.inner.write().from_listener.remove(&listener_ip(3));
+        assert!(!resolver.peer_map_consistency());
+        resolver.reconcile_peer_maps();
+        assert!(resolver.peer_map_consistency());
+        assert!(resolver.get_listener(peer_addr(3)).is_none());
+        assert!(resolver.get_ambiguous(listener_ip(3)).is_none());
+        assert!(resolver.get_address(listener_ip(3)).is_none());
+        assert!(resolver.get_peer_ip_for_address(addresses[0]).is_none());
+
+        // Ensure the untouched peer remains.
+        assert_eq!(resolver.get_listener(peer_addr(2)).unwrap(), listener_ip(2));
+        assert_eq!(resolver.get_ambiguous(listener_ip(2)).unwrap(), peer_addr(2));
+        assert_eq!(resolver.get_address(listener_ip(2)).unwrap(), addresses[2]);
+        assert_eq!(resolver.get_peer_ip_for_address(addresses[2]).unwrap(), listener_ip(2));
+        assert_eq!(resolver.mapped_peer_count(), 1);
+    }
+
+    #[test]
+    fn test_resolver_concurrent_insert_remove() {
+        let resolver = std::sync::Arc::new(Resolver::<CurrentNetwork>::new());
+        let mut rng = TestRng::default();
+        let addresses = (0..4).map(|_| Address::<CurrentNetwork>::new(rng.gen())).collect::<Vec<_>>();
+
+        // Concurrently insert and remove the same peers from several threads.
+        let handles = (0..4u16)
+            .map(|i| {
+                let resolver = resolver.clone();
+                let addresses = addresses.clone();
+                std::thread::spawn(move || {
+                    for j in 0..1000u16 {
+                        let index = (i + j) % 4;
+                        // Reconnect from a different (ambiguous) peer address each time.
+                        let reconnect_addr = peer_addr(index * 100 + (j / 2) % 100);
+                        match j % 2 {
+                            0 => {
+                                resolver.insert_peer(listener_ip(index), reconnect_addr, addresses[usize::from(index)])
+                            }
+                            _ => resolver.remove_peer(listener_ip(index)),
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // Ensure the peer maps are still inverses of each other.
+        assert!(resolver.peer_map_consistency());
+        let inner = resolver.inner.read();
+        assert_eq!(inner.from_listener.len(), inner.to_listener.len());
+        assert_eq!(inner.peer_addresses.len(), inner.address_peers.len());
+        for (listener_ip, address) in inner.peer_addresses.iter() {
+            assert_eq!(inner.address_peers.get(address), Some(listener_ip));
+        }
+        for (listener_ip, peer_addr) in inner.from_listener.iter() {
+            assert_eq!(inner.to_listener.get(peer_addr), Some(listener_ip));
+        }
+    }
 }
@@ -150,7 +150,9 @@ impl<N: Network> Storage<N> {
     /// Note: This method is only called once per round, upon certification of the primary's batch.
     pub fn increment_to_next_round(&self, current_round: u64) -> Result<u64> {
         // Determine the next round.
-        let next_round = current_round + 1;
+        let Some(next_round) = current_round.checked_add(1) else {
+            bail!("The next round overflows (current round {current_round})")
+        };
 
         // Check if the next round is less than the current round in storage.
         {
@@ -891,6 +893,25 @@ mod tests {
         assert_storage(&storage, &[], &[], &[], &Default::default());
     }
 
+    #[test]
+    fn test_increment_to_next_round_overflow() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee.
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        // Initialize the ledger.
+        let ledger = Arc::new(MockLedgerService::new(committee));
+        // Initialize the storage.
+        let storage = Storage::<CurrentNetwork>::new(ledger, Arc::new(BFTMemoryService::new()), 1);
+        // Retrieve the current round.
+        let current_round = storage.current_round();
+
+        // Ensure the round can not wrap around.
+        assert!(storage.increment_to_next_round(u64::MAX).is_err());
+        // Ensure the current round is unchanged.
+        assert_eq!(storage.current_round(), current_round);
+    }
+
     #[test]
     fn test_certificate_duplicate() {
         let rng = &mut TestRng::default();
@@ -14,11 +14,18 @@
 
 pub(super) const COUNTER_NAMES: [&str; 1] = [bft::LEADERS_ELECTED];
 
-pub(super) const GAUGE_NAMES: [&str; 12] = [
+pub(super) const GAUGE_NAMES: [&str; 19] = [
+    bft::COMMITTEE_CONNECTED_STAKE,
+    bft::COMMITTEE_SIZE,
+    bft::COMMITTEE_TOTAL_STAKE,
     bft::CONNECTED,
     bft::CONNECTING,
     bft::LAST_STORED_ROUND,
     bft::PROPOSAL_ROUND,
+    bft::RESOLVER_ADDRESS_PEERS,
+    bft::RESOLVER_FROM_LISTENER,
+    bft::RESOLVER_PEER_ADDRESSES,
+    bft::RESOLVER_TO_LISTENER,
     blocks::HEIGHT,
     blocks::TRANSACTIONS,
     consensus::COMMITTED_CERTIFICATES,
@@ -41,11 +48,18 @@ pub(super) const HISTOGRAM_NAMES: [&str; 7] = [
 
 pub mod bft {
     pub const COMMIT_ROUNDS_LATENCY: &str = "snarkos_bft_commit_rounds_latency_secs"; // <-- This one doesn't even make sense.
+    pub const COMMITTEE_CONNECTED_STAKE: &str = "snarkos_bft_committee_connected_stake";
+    pub const COMMITTEE_SIZE: &str = "snarkos_bft_committee_size_total";
+    pub const COMMITTEE_TOTAL_STAKE: &str = "snarkos_bft_committee_total_stake";
     pub const CONNECTED: &str = "snarkos_bft_connected_total";
     pub const CONNECTING: &str = "snarkos_bft_connecting_total";
     pub const LAST_STORED_ROUND: &str = "snarkos_bft_last_stored_round";
     pub const LEADERS_ELECTED: &str = "snarkos_bft_leaders_elected_total";
     pub const PROPOSAL_ROUND: &str = "snarkos_bft_primary_proposal_round";
+    pub const RESOLVER_ADDRESS_PEERS: &str = "snarkos_bft_resolver_address_peers_total";
+    pub const RESOLVER_FROM_LISTENER: &str = "snarkos_bft_resolver_from_listener_total";
+    pub const RESOLVER_PEER_ADDRESSES: &str = "snarkos_bft_resolver_peer_addresses_total";
+    pub const RESOLVER_TO_LISTENER: &str = "snarkos_bft_resolver_to_listener_total";
 }
 
 pub mod blocks {
@@ -73,3 +87,26 @@ pub mod tcp {
     pub const NOISE_CODEC_DECRYPTION_SIZE: &str = "snarkos_tcp_noise_codec_decryption_size";
     pub const TCP_TASKS: &str = "snarkos_tcp_tasks_total";
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_committee_metrics_are_registered() {
+        // Ensure the committee metrics are registered on init.
+        assert!(GAUGE_NAMES.contains(&bft::COMMITTEE_SIZE));
+        assert!(GAUGE_NAMES.contains(&bft::COMMITTEE_TOTAL_STAKE));
+        assert!(GAUGE_NAMES.contains(&bft::COMMITTEE_CONNECTED_STAKE));
+        assert!(COUNTER_NAMES.contains(&bft::LEADERS_ELECTED));
+    }
+
+    #[test]
+    fn test_resolver_metrics_are_registered() {
+        // Ensure the resolver peer map gauges are registered on init.
+        assert!(GAUGE_NAMES.contains(&bft::RESOLVER_ADDRESS_PEERS));
+        assert!(GAUGE_NAMES.contains(&bft::RESOLVER_FROM_LISTENER));
+        assert!(GAUGE_NAMES.contains(&bft::RESOLVER_PEER_ADDRESSES));
+        assert!(GAUGE_NAMES.contains(&bft::RESOLVER_TO_LISTENER));
+    }
+}